@@ -2,53 +2,112 @@ use Graph;
 
 use std::ops::Index;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::collections::hash_map::Keys;
 
 pub type NodeId = usize;
-type ListId = usize;
 
 pub struct AdjacencyMapNode<V, W> {
     pub value: V,
     pub outgoing: HashMap<NodeId, W>,
+    incoming: HashSet<NodeId>,
 }
 
+/// A dynamic, editable graph keyed by automatically-allocated `NodeId`s.
+///
+/// Removed slots are tracked on a free list and reused by later `add_node` calls, so node handles
+/// stay stable across removals: removing one node never changes the id of any other.
 pub struct AdjacencyMap<V, W> {
-    nodes: Vec<AdjacencyMapNode<V, W>>,
-    map: HashMap<NodeId, ListId>,
+    nodes: Vec<Option<AdjacencyMapNode<V, W>>>,
+    free: Vec<NodeId>,
 }
 
 impl<V, W> AdjacencyMap<V, W> {
     pub fn new() -> AdjacencyMap<V, W> {
         AdjacencyMap {
             nodes: Vec::new(),
-            map: HashMap::new(),
+            free: Vec::new(),
         }
     }
 
-    pub fn add_node(&mut self, node_id: NodeId, value: V) {
-        let list_id = self.nodes.len();
-        self.nodes.push(AdjacencyMapNode {
-            value: value,
-            outgoing: HashMap::new(),
-        });
-        self.map.insert(node_id, list_id);
+    pub fn add_node(&mut self, value: V) -> NodeId {
+        let node = AdjacencyMapNode { value, outgoing: HashMap::new(), incoming: HashSet::new() };
+        match self.free.pop() {
+            Some(node_id) => {
+                self.nodes[node_id] = Some(node);
+                node_id
+            }
+            None => {
+                let node_id = self.nodes.len();
+                self.nodes.push(Some(node));
+                node_id
+            }
+        }
     }
 
     pub fn add_arc(&mut self, from: NodeId, to: NodeId, weight: W) {
-        self.nodes[self.map[from]].outgoing.insert(to, weight);
+        self.nodes[from].as_mut().expect("Node does not exist").outgoing.insert(to, weight);
+        self.nodes[to].as_mut().expect("Node does not exist").incoming.insert(from);
     }
 
     pub fn add_edge(&mut self, a: NodeId, b: NodeId, weight: W) where W: Clone {
         self.add_arc(a, b, weight.clone());
         self.add_arc(b, a, weight.clone());
     }
+
+    pub fn remove_edge(&mut self, from: NodeId, to: NodeId) {
+        if let Some(node) = self.nodes[from].as_mut() {
+            node.outgoing.remove(&to);
+        }
+        if let Some(node) = self.nodes[to].as_mut() {
+            node.incoming.remove(&from);
+        }
+    }
+
+    /// Removes `node_id` and every arc referencing it, in O(degree).
+    pub fn remove_node(&mut self, node_id: NodeId) {
+        let node = match self.nodes[node_id].take() {
+            Some(node) => node,
+            None => return,
+        };
+
+        for predecessor in &node.incoming {
+            if let Some(predecessor) = self.nodes[*predecessor].as_mut() {
+                predecessor.outgoing.remove(&node_id);
+            }
+        }
+        for target in node.outgoing.keys() {
+            if let Some(target) = self.nodes[*target].as_mut() {
+                target.incoming.remove(&node_id);
+            }
+        }
+
+        self.free.push(node_id);
+    }
+
+    pub fn node_ids(&self) -> impl Iterator<Item=NodeId> + '_ {
+        self.nodes.iter().enumerate().filter_map(|(id, node)| node.as_ref().map(|_| id))
+    }
+
+    pub fn value(&self, node_id: NodeId) -> &V {
+        &self.nodes[node_id].as_ref().expect("Node does not exist").value
+    }
+
+    pub fn incoming_edges(&self, node_id: NodeId) -> impl Iterator<Item=(NodeId, &W)> {
+        self.nodes[node_id]
+            .as_ref()
+            .expect("Node does not exist")
+            .incoming
+            .iter()
+            .map(move |&from| (from, &self.nodes[from].as_ref().unwrap().outgoing[&node_id]))
+    }
 }
 
 impl<V, W> Index<NodeId> for AdjacencyMap<V, W> {
     type Output = AdjacencyMapNode<V, W>;
 
-    fn index(&self, index: &NodeId) -> &AdjacencyMapNode<V, W> {
-        &self.nodes[self.map[*index]]
+    fn index(&self, index: NodeId) -> &AdjacencyMapNode<V, W> {
+        self.nodes[index].as_ref().expect("Node does not exist")
     }
 }
 
@@ -76,21 +135,87 @@ impl<'a, N, W> Graph for &'a AdjacencyMap<N, W> where W: Clone {
     type OutgoingEdgesIter = OutgoingEdgesIter<'a, W>;
 
     fn target(&self, edge: &(NodeId, NodeId)) -> Option<NodeId> {
-        if self.map.contains_key(&edge.1) { Some(edge.1) }
-        else { None }
+        match self.nodes.get(edge.1) {
+            Some(Some(_)) => Some(edge.1),
+            _ => None,
+        }
     }
 
     fn weight(&self, edge: &(NodeId, NodeId)) -> W {
         let &(from, to) = edge;
-        let node = self.map.get(&from).expect("Edge does not exist");
-        self.nodes[*node].outgoing.get(&to).cloned().expect("Target node does not exist")
+        let node = self.nodes[from].as_ref().expect("Edge does not exist");
+        node.outgoing.get(&to).cloned().expect("Target node does not exist")
     }
 
     fn outgoing_edges(&self, node: &NodeId) -> OutgoingEdgesIter<'a, W> {
         OutgoingEdgesIter {
             from: *node,
-            iter_base: self.nodes[self.map[*node]].outgoing.keys(),
+            iter_base: self.nodes[*node].as_ref().expect("Node does not exist").outgoing.keys(),
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_node_drops_incoming_arcs() {
+        // [1] -> [2] -> [3]
+        let mut graph = AdjacencyMap::new();
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+
+        graph.add_arc(n1, n2, 0);
+        graph.add_arc(n2, n3, 0);
+
+        graph.remove_node(n2);
+
+        assert_eq!((&graph).outgoing_edges(&n1).count(), 0);
+        assert_eq!(graph.incoming_edges(n3).count(), 0);
+    }
+
+    #[test]
+    fn test_remove_node_reuses_its_slot() {
+        let mut graph: AdjacencyMap<(), i32> = AdjacencyMap::new();
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+
+        graph.remove_node(n1);
+        let n3 = graph.add_node(());
+
+        assert_eq!(n3, n1);
+        assert_eq!(graph.node_ids().collect::<HashSet<_>>(), [n3, n2].iter().cloned().collect());
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut graph = AdjacencyMap::new();
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 5);
+        graph.remove_edge(n1, n2);
+
+        // `remove_edge` only severs the n1 -> n2 arc; the n2 -> n1 arc added by `add_edge` is
+        // untouched, so n1 still has an incoming edge from n2.
+        assert_eq!((&graph).outgoing_edges(&n1).count(), 0);
+        assert_eq!(graph.incoming_edges(n2).count(), 0);
+    }
+
+    #[test]
+    fn test_incoming_edges() {
+        let mut graph = AdjacencyMap::new();
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+
+        graph.add_arc(n1, n3, 4);
+        graph.add_arc(n2, n3, 7);
+
+        let mut incoming: Vec<_> = graph.incoming_edges(n3).map(|(from, &w)| (from, w)).collect();
+        incoming.sort();
+        assert_eq!(incoming, vec![(n1, 4), (n2, 7)]);
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::iter::Copied;
+use std::ops::Range;
+use std::slice::Iter;
+
+use crate::{AdjacencyMap, Graph};
+
+pub type NodeId = usize;
+
+/// An immutable graph stored in Compressed Sparse Row form.
+///
+/// Outgoing edges of node `i` live in the contiguous slice
+/// `targets[offsets[i]..offsets[i + 1]]`, sorted by target, so `outgoing_edges` is a
+/// zero-allocation slice iterator and `weight` is a binary search over that slice.
+pub struct Csr<V, W> {
+    values: Vec<V>,
+    offsets: Vec<usize>,
+    targets: Vec<NodeId>,
+    weights: Vec<W>,
+}
+
+impl<V, W> Csr<V, W> {
+    pub fn builder() -> CsrBuilder<V, W> {
+        CsrBuilder::new()
+    }
+
+    pub fn num_nodes(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn value(&self, node: NodeId) -> &V {
+        &self.values[node]
+    }
+
+    fn row(&self, node: NodeId) -> Range<usize> {
+        self.offsets[node]..self.offsets[node + 1]
+    }
+}
+
+/// Builds a [`Csr`] from an edge list, sorting and compacting it into row form on `build`.
+pub struct CsrBuilder<V, W> {
+    values: Vec<V>,
+    edges: Vec<(NodeId, NodeId, W)>,
+}
+
+impl<V, W> CsrBuilder<V, W> {
+    pub fn new() -> CsrBuilder<V, W> {
+        CsrBuilder { values: Vec::new(), edges: Vec::new() }
+    }
+
+    pub fn add_node(&mut self, value: V) -> NodeId {
+        let id = self.values.len();
+        self.values.push(value);
+        id
+    }
+
+    pub fn add_arc(&mut self, from: NodeId, to: NodeId, weight: W) {
+        self.edges.push((from, to, weight));
+    }
+
+    pub fn add_edge(&mut self, a: NodeId, b: NodeId, weight: W)
+    where
+        W: Clone,
+    {
+        self.add_arc(a, b, weight.clone());
+        self.add_arc(b, a, weight);
+    }
+
+    pub fn build(mut self) -> Csr<V, W> {
+        self.edges.sort_by_key(|&(from, to, _)| (from, to));
+
+        let num_nodes = self.values.len();
+        let mut offsets = vec![0; num_nodes + 1];
+        for &(from, _, _) in &self.edges {
+            offsets[from + 1] += 1;
+        }
+        for i in 0..num_nodes {
+            offsets[i + 1] += offsets[i];
+        }
+
+        let mut targets = Vec::with_capacity(self.edges.len());
+        let mut weights = Vec::with_capacity(self.edges.len());
+        for (_, to, weight) in self.edges {
+            targets.push(to);
+            weights.push(weight);
+        }
+
+        Csr { values: self.values, offsets, targets, weights }
+    }
+}
+
+impl<'a, V, W> From<&'a AdjacencyMap<V, W>> for Csr<V, W>
+where
+    V: Clone,
+    W: Clone,
+{
+    fn from(graph: &'a AdjacencyMap<V, W>) -> Csr<V, W> {
+        let mut builder = CsrBuilder::new();
+
+        let mut csr_id = HashMap::new();
+        for node_id in graph.node_ids() {
+            csr_id.insert(node_id, builder.add_node(graph.value(node_id).clone()));
+        }
+        for node_id in graph.node_ids() {
+            for edge in graph.outgoing_edges(&node_id) {
+                if let Some(target) = graph.target(&edge) {
+                    builder.add_arc(csr_id[&node_id], csr_id[&target], graph.weight(&edge));
+                }
+            }
+        }
+
+        builder.build()
+    }
+}
+
+pub struct CsrOutgoingEdgesIter<'a> {
+    from: NodeId,
+    iter_base: Copied<Iter<'a, NodeId>>,
+}
+
+impl<'a> Iterator for CsrOutgoingEdgesIter<'a> {
+    type Item = (NodeId, NodeId);
+    fn next(&mut self) -> Option<(NodeId, NodeId)> {
+        self.iter_base.next().map(|to| (self.from, to))
+    }
+}
+
+impl<'a, V, W> Graph for &'a Csr<V, W>
+where
+    W: Clone,
+{
+    type NodeId = NodeId;
+    type Edge = (NodeId, NodeId);
+    type Weight = W;
+    type OutgoingEdgesIter = CsrOutgoingEdgesIter<'a>;
+
+    fn target(&self, edge: &(NodeId, NodeId)) -> Option<NodeId> {
+        let &(_, to) = edge;
+        if to < self.values.len() { Some(to) } else { None }
+    }
+
+    fn weight(&self, edge: &(NodeId, NodeId)) -> W {
+        let &(from, to) = edge;
+        let row = self.row(from);
+        let idx = self.targets[row.clone()].binary_search(&to).expect("Target node does not exist");
+        self.weights[row.start + idx].clone()
+    }
+
+    fn outgoing_edges(&self, node: &NodeId) -> CsrOutgoingEdgesIter<'a> {
+        let row = self.row(*node);
+        CsrOutgoingEdgesIter { from: *node, iter_base: self.targets[row].iter().copied() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_and_query() {
+        //  [0] --2-- [1]
+        //      \      |
+        //        1    2
+        //          \  |
+        //  [2] --3-- [3]
+        let mut builder = Csr::builder();
+        for _ in 0..4 {
+            builder.add_node(());
+        }
+        builder.add_edge(0, 1, 2);
+        builder.add_edge(0, 3, 1);
+        builder.add_edge(1, 3, 2);
+        builder.add_edge(2, 3, 3);
+        let csr = builder.build();
+
+        assert_eq!(csr.num_nodes(), 4);
+
+        let mut outgoing: Vec<_> =
+            (&csr).outgoing_edges(&0).map(|e| ((&csr).target(&e).unwrap(), (&csr).weight(&e))).collect();
+        outgoing.sort();
+        assert_eq!(outgoing, vec![(1, 2), (3, 1)]);
+    }
+
+    #[test]
+    fn test_from_adjacency_map() {
+        let mut graph = AdjacencyMap::new();
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 5);
+        graph.add_edge(n2, n3, 7);
+
+        let csr = Csr::from(&graph);
+        assert_eq!(csr.num_nodes(), 3);
+
+        let total: u32 = (0..csr.num_nodes())
+            .flat_map(|n| (&csr).outgoing_edges(&n).map(|e| (&csr).weight(&e)))
+            .sum();
+        assert_eq!(total, 5 + 5 + 7 + 7);
+    }
+}
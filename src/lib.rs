@@ -3,11 +3,13 @@
 //! A WIP library for graph representations and algorithms
 
 pub mod adjacency_map;
+pub mod csr;
 pub mod shortest_path;
 pub mod minimum_spanning_tree;
 pub mod traversal;
 
 pub use adjacency_map::AdjacencyMap;
+pub use csr::Csr;
 
 pub trait Graph {
     type NodeId;
@@ -1,6 +1,6 @@
 use std::{
     cmp::{Ordering, PartialOrd},
-    collections::{BinaryHeap, HashSet},
+    collections::{BinaryHeap, HashMap, HashSet},
     hash::Hash,
 };
 
@@ -47,6 +47,94 @@ where
     edges
 }
 
+/// Like `prims`, but returns a spanning *forest* over the nodes in `nodes`, without requiring the
+/// graph to be connected or a start node to be designated. Only edges leaving nodes in `nodes`
+/// are considered, and the function panics if such an edge leads to a node not in `nodes`.
+pub fn kruskals<G: Graph>(graph: &G, nodes: impl IntoIterator<Item = G::NodeId>) -> Vec<G::Edge>
+where
+    G::NodeId: Hash + Eq + Clone,
+    G::Edge: Clone,
+    G::Weight: Ord,
+{
+    let mut union_find = UnionFind::new();
+    let mut edges = Vec::new();
+    let mut num_nodes = 0;
+    for node in nodes {
+        union_find.make_set(node.clone());
+        num_nodes += 1;
+        for edge in graph.outgoing_edges(&node) {
+            let weight = graph.weight(&edge);
+            edges.push((node.clone(), edge, weight));
+        }
+    }
+    edges.sort_by(|a, b| a.2.cmp(&b.2));
+
+    let mut mst = Vec::new();
+    for (from, edge, _) in edges {
+        let to = match graph.target(&edge) {
+            Some(n) => n,
+            None => continue,
+        };
+        if union_find.union(&from, &to) {
+            mst.push(edge);
+            if mst.len() + 1 == num_nodes {
+                break;
+            }
+        }
+    }
+
+    mst
+}
+
+/// A disjoint-set structure over `G::NodeId`, used by `kruskals` to detect when an edge would
+/// connect two nodes that are already in the same tree.
+struct UnionFind<N> {
+    index: HashMap<N, usize>,
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+impl<N: Hash + Eq + Clone> UnionFind<N> {
+    fn new() -> UnionFind<N> {
+        UnionFind { index: HashMap::new(), parent: Vec::new(), rank: Vec::new() }
+    }
+
+    fn make_set(&mut self, node: N) {
+        if self.index.contains_key(&node) {
+            return;
+        }
+        let id = self.parent.len();
+        self.parent.push(id);
+        self.rank.push(0);
+        self.index.insert(node, id);
+    }
+
+    fn find(&mut self, id: usize) -> usize {
+        if self.parent[id] != id {
+            self.parent[id] = self.find(self.parent[id]);
+        }
+        self.parent[id]
+    }
+
+    /// Unions the sets containing `a` and `b`, returning `true` if they were previously disjoint.
+    fn union(&mut self, a: &N, b: &N) -> bool {
+        let (a, b) = (self.index[a], self.index[b]);
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+        match self.rank[root_a].cmp(&self.rank[root_b]) {
+            Ordering::Less => self.parent[root_a] = root_b,
+            Ordering::Greater => self.parent[root_b] = root_a,
+            Ordering::Equal => {
+                self.parent[root_b] = root_a;
+                self.rank[root_a] += 1;
+            }
+        }
+        true
+    }
+}
+
 struct EdgeContainer<E, W> {
     cost: W,
     edge: E,
@@ -86,7 +174,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::prims;
+    use super::{kruskals, prims};
     use crate::AdjacencyMap;
     use crate::Graph;
 
@@ -99,18 +187,61 @@ mod tests {
         //  [3] --3-- [4]
         let mut graph = AdjacencyMap::new();
 
-        graph.add_node(1, ());
-        graph.add_node(2, ());
-        graph.add_node(3, ());
-        graph.add_node(4, ());
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 2);
+        graph.add_edge(n1, n4, 1);
+        graph.add_edge(n2, n4, 2);
+        graph.add_edge(n3, n4, 3);
+
+        let mst = prims(&&graph, n1);
+        let total = mst.iter().map(|e| (&graph).weight(&e)).fold(0, |acc, x| acc + x);
+        assert_eq!(total, 3 + 1 + 2);
+    }
+
+    #[test]
+    fn test_kruskals_simple() {
+        //  [1] --2-- [2]
+        //      \      |
+        //        1    2
+        //          \  |
+        //  [3] --3-- [4]
+        let mut graph = AdjacencyMap::new();
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
 
-        graph.add_edge(1, 2, 2);
-        graph.add_edge(1, 4, 1);
-        graph.add_edge(2, 4, 2);
-        graph.add_edge(3, 4, 3);
+        graph.add_edge(n1, n2, 2);
+        graph.add_edge(n1, n4, 1);
+        graph.add_edge(n2, n4, 2);
+        graph.add_edge(n3, n4, 3);
 
-        let mst = prims(&&graph, 1);
+        let mst = kruskals(&&graph, graph.node_ids());
         let total = mst.iter().map(|e| (&graph).weight(&e)).fold(0, |acc, x| acc + x);
         assert_eq!(total, 3 + 1 + 2);
     }
+
+    #[test]
+    fn test_kruskals_disconnected() {
+        //  [1] --1-- [2]       [3] --5-- [4]
+        let mut graph = AdjacencyMap::new();
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 1);
+        graph.add_edge(n3, n4, 5);
+
+        let forest = kruskals(&&graph, graph.node_ids());
+        assert_eq!(forest.len(), 2);
+        let total = forest.iter().map(|e| (&graph).weight(&e)).fold(0, |acc, x| acc + x);
+        assert_eq!(total, 1 + 5);
+    }
 }
@@ -79,6 +79,230 @@ where
     None
 }
 
+/// Runs a single-source Dijkstra search, returning the shortest distance and a predecessor map
+/// for every node reachable from `start`. Unlike `a_star`, this answers "distance to every
+/// reachable node" in one pass rather than searching towards a single target.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::NodeId,
+) -> (HashMap<G::NodeId, G::Weight>, HashMap<G::NodeId, G::NodeId>)
+where
+    G::NodeId: Hash + Eq + Clone,
+    G::Edge: Clone,
+    G::Weight: Clone + Ord + Add<Output = G::Weight> + Zero,
+{
+    let mut dist = HashMap::new();
+    let mut predecessors = HashMap::new();
+
+    let mut frontier: BinaryHeap<DijkstraEntry<G::NodeId, G::Weight>> = BinaryHeap::new();
+    dist.insert(start.clone(), num_traits::zero());
+    frontier.push(DijkstraEntry { cost: num_traits::zero(), node: start });
+
+    while let Some(DijkstraEntry { cost, node }) = frontier.pop() {
+        // Lazy deletion: this entry was superseded by a cheaper one pushed after it.
+        if dist.get(&node).map_or(false, |best| cost > *best) {
+            continue;
+        }
+
+        for edge in graph.outgoing_edges(&node) {
+            let target = match graph.target(&edge) {
+                Some(n) => n,
+                None => continue,
+            };
+            let next_cost = cost.clone() + graph.weight(&edge);
+            let is_better = match dist.get(&target) {
+                Some(best) => next_cost < *best,
+                None => true,
+            };
+            if is_better {
+                dist.insert(target.clone(), next_cost.clone());
+                predecessors.insert(target.clone(), node.clone());
+                frontier.push(DijkstraEntry { cost: next_cost, node: target });
+            }
+        }
+    }
+
+    (dist, predecessors)
+}
+
+/// Follows `predecessors` (as produced by `dijkstra`) from `end` back to `start`, returning the
+/// path in `start`-to-`end` order, or `None` if `end` is unreachable from `start`.
+pub fn reconstruct_path<N>(predecessors: &HashMap<N, N>, start: &N, end: &N) -> Option<Vec<N>>
+where
+    N: Hash + Eq + Clone,
+{
+    let mut path = vec![end.clone()];
+    let mut current = end;
+    while current != start {
+        current = predecessors.get(current)?;
+        path.push(current.clone());
+    }
+    path.reverse();
+    Some(path)
+}
+
+struct DijkstraEntry<N, W> {
+    cost: W,
+    node: N,
+}
+
+//
+// Boilerplate code for implementing Ord for DijkstraEntry, ensuring that it is implemented so
+// that elements placed in a binary heap will form a min queue.
+//
+
+impl<N, W: Eq> PartialEq for DijkstraEntry<N, W> {
+    fn eq(&self, other: &DijkstraEntry<N, W>) -> bool {
+        self.cost == other.cost
+    }
+}
+impl<N, W: Eq> Eq for DijkstraEntry<N, W> {}
+
+impl<N, W: Ord> PartialOrd for DijkstraEntry<N, W> {
+    fn partial_cmp(&self, other: &DijkstraEntry<N, W>) -> Option<Ordering> {
+        other.cost.partial_cmp(&self.cost)
+    }
+}
+impl<N, W: Ord> Ord for DijkstraEntry<N, W> {
+    fn cmp(&self, other: &DijkstraEntry<N, W>) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+/// The outcome of a budgeted search: either a complete path, a best-effort path to the node
+/// closest to the goal seen before the iteration budget ran out, or proof that no path exists.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PathResult<N> {
+    Found(Vec<N>),
+    PartialBudgetExhausted(Vec<N>),
+    NotFound,
+}
+
+/// The state of an in-progress `a_star_budgeted` search, opaque to callers beyond construction so
+/// that a search can be paused and resumed across multiple budgeted calls.
+pub struct SearchState<N, W> {
+    dist_map: HashMap<N, PathNode<N, W>>,
+    frontier: BinaryHeap<PathNode<N, W>>,
+    // The frontier node with the lowest heuristic-to-goal seen so far, used as a best-effort
+    // target if the budget runs out before a complete path is found. Kept on the state (rather
+    // than scoped to a single call) so a call whose whole budget is spent skipping stale,
+    // already-superseded frontier entries still reports the best node found by earlier calls
+    // instead of spuriously giving up.
+    closest: Option<PathNode<N, W>>,
+}
+
+impl<N, W> SearchState<N, W>
+where
+    W: Clone + Ord + Add<Output = W> + Zero,
+{
+    pub fn new(start: N) -> SearchState<N, W> {
+        let mut frontier = BinaryHeap::new();
+        frontier.push(PathNode::new(start, None));
+        SearchState { dist_map: HashMap::new(), frontier, closest: None }
+    }
+}
+
+/// Runs `a_star` for at most `max_iterations` pops of the frontier, starting from a fresh search
+/// state. See `a_star_resume` for continuing a search across multiple calls.
+pub fn a_star_budgeted<G: Graph>(
+    graph: &G,
+    start: G::NodeId,
+    end: G::NodeId,
+    heuristic: Heuristic<G::NodeId, G::Weight>,
+    max_iterations: usize,
+) -> PathResult<G::NodeId>
+where
+    G::NodeId: Hash + Eq + Clone,
+    G::Edge: Clone,
+    G::Weight: Clone + Ord + PartialOrd + Add + Zero,
+{
+    let mut state = SearchState::new(start);
+    a_star_resume(graph, end, heuristic, max_iterations, &mut state)
+}
+
+/// Continues an `a_star` search from a previously returned `SearchState`, for callers that must
+/// bound the work done per call (e.g. a game agent bounding pathfinding to a frame budget).
+pub fn a_star_resume<G: Graph>(
+    graph: &G,
+    end: G::NodeId,
+    heuristic: Heuristic<G::NodeId, G::Weight>,
+    max_iterations: usize,
+    state: &mut SearchState<G::NodeId, G::Weight>,
+) -> PathResult<G::NodeId>
+where
+    G::NodeId: Hash + Eq + Clone,
+    G::Edge: Clone,
+    G::Weight: Clone + Ord + PartialOrd + Add + Zero,
+{
+    for _ in 0..max_iterations {
+        let active_val = match state.frontier.pop() {
+            Some(v) => v,
+            None => return PathResult::NotFound,
+        };
+
+        if active_val.node == end {
+            return PathResult::Found(path_from_dist_map(&state.dist_map, active_val));
+        }
+
+        let active_val = match state.dist_map.entry(active_val.node.clone()) {
+            Entry::Occupied(mut e) => {
+                if active_val.path_cost < e.get().path_cost {
+                    e.insert(active_val.clone());
+                    active_val
+                }
+                else {
+                    continue;
+                }
+            }
+            Entry::Vacant(e) => {
+                e.insert(active_val.clone());
+                active_val
+            }
+        };
+
+        if state.closest.as_ref().map_or(true, |c| active_val.heuristic_cost < c.heuristic_cost) {
+            state.closest = Some(active_val.clone());
+        }
+
+        for edge in graph.outgoing_edges(&active_val.node) {
+            let target = match graph.target(&edge) {
+                Some(n) => n,
+                None => continue,
+            };
+            if !state.dist_map.contains_key(&target) {
+                let mut next_val = PathNode::new(target, Some(active_val.node.clone()));
+                next_val.path_cost = active_val.path_cost.clone() + graph.weight(&edge);
+                next_val.heuristic_cost = heuristic(&next_val.node, &end);
+                state.frontier.push(next_val);
+            }
+        }
+    }
+
+    match state.closest.clone() {
+        Some(node) => PathResult::PartialBudgetExhausted(path_from_dist_map(&state.dist_map, node)),
+        // No node has been expanded yet (a zero budget), so there is no partial path to report.
+        None if !state.frontier.is_empty() => PathResult::PartialBudgetExhausted(vec![]),
+        None => PathResult::NotFound,
+    }
+}
+
+/// Follows `parent` links from `node` back through `dist_map` to recover the path taken to reach
+/// it, in goal-to-start order (matching `a_star`'s own reconstruction).
+fn path_from_dist_map<N, W>(dist_map: &HashMap<N, PathNode<N, W>>, mut node: PathNode<N, W>) -> Vec<N>
+where
+    N: Hash + Eq + Clone,
+    W: Clone,
+{
+    let mut path = vec![];
+    loop {
+        path.push(node.node.clone());
+        node = match node.parent.clone() {
+            Some(v) => dist_map[&v].clone(),
+            None => return path,
+        };
+    }
+}
+
 #[derive(Clone)]
 struct PathNode<N, W> {
     node: N,
@@ -144,40 +368,170 @@ mod tests {
     fn test_simple() {
         let mut graph = AdjacencyMap::new();
 
-        graph.add_node(1, ());
-        graph.add_node(2, ());
-        graph.add_node(3, ());
-        graph.add_node(4, ());
-        graph.add_node(5, ());
-        graph.add_node(6, ());
-
-        graph.add_edge(1, 2, 7);
-        graph.add_edge(1, 3, 9);
-        graph.add_edge(1, 6, 14);
-        graph.add_edge(2, 3, 10);
-        graph.add_edge(2, 4, 15);
-        graph.add_edge(3, 4, 11);
-        graph.add_edge(3, 6, 2);
-        graph.add_edge(4, 5, 6);
-        graph.add_edge(5, 6, 14);
-
-        let (start, end) = (1, 5);
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+        let n5 = graph.add_node(());
+        let n6 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 7);
+        graph.add_edge(n1, n3, 9);
+        graph.add_edge(n1, n6, 14);
+        graph.add_edge(n2, n3, 10);
+        graph.add_edge(n2, n4, 15);
+        graph.add_edge(n3, n4, 11);
+        graph.add_edge(n3, n6, 2);
+        graph.add_edge(n4, n5, 6);
+        graph.add_edge(n5, n6, 14);
+
+        let (start, end) = (n1, n5);
         let path = a_star(&&graph, start, end, no_heuristic);
         assert!(path.is_some());
-        assert_eq!(path.unwrap(), vec![5, 6, 3, 1]);
+        assert_eq!(path.unwrap(), vec![n5, n6, n3, n1]);
     }
 
     #[test]
     fn test_simple_no_path() {
         let mut graph = AdjacencyMap::new();
 
-        graph.add_node(1, ());
-        graph.add_node(2, ());
-        graph.add_node(3, ());
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
 
-        graph.add_edge(1, 2, 1);
-        let (start, end) = (1, 3);
+        graph.add_edge(n1, n2, 1);
+        let (start, end) = (n1, n3);
         let path = a_star(&&graph, start, end, no_heuristic);
         assert!(path.is_none());
     }
+
+    #[test]
+    fn test_budgeted_finds_same_path_as_a_star() {
+        let mut graph = AdjacencyMap::new();
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+        let n5 = graph.add_node(());
+        let n6 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 7);
+        graph.add_edge(n1, n3, 9);
+        graph.add_edge(n1, n6, 14);
+        graph.add_edge(n2, n3, 10);
+        graph.add_edge(n2, n4, 15);
+        graph.add_edge(n3, n4, 11);
+        graph.add_edge(n3, n6, 2);
+        graph.add_edge(n4, n5, 6);
+        graph.add_edge(n5, n6, 14);
+
+        let (start, end) = (n1, n5);
+        let result = a_star_budgeted(&&graph, start, end, no_heuristic, 100);
+        assert_eq!(result, PathResult::Found(vec![n5, n6, n3, n1]));
+    }
+
+    #[test]
+    fn test_budgeted_exhausted_returns_partial_path() {
+        let mut graph = AdjacencyMap::new();
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+        let n5 = graph.add_node(());
+        let n6 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 7);
+        graph.add_edge(n1, n3, 9);
+        graph.add_edge(n1, n6, 14);
+        graph.add_edge(n2, n3, 10);
+        graph.add_edge(n2, n4, 15);
+        graph.add_edge(n3, n4, 11);
+        graph.add_edge(n3, n6, 2);
+        graph.add_edge(n4, n5, 6);
+        graph.add_edge(n5, n6, 14);
+
+        let (start, end) = (n1, n5);
+        match a_star_budgeted(&&graph, start, end, no_heuristic, 1) {
+            PathResult::PartialBudgetExhausted(path) => assert_eq!(path, vec![n1]),
+            other => panic!("expected a partial path, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resume_continues_a_budgeted_search() {
+        let mut graph = AdjacencyMap::new();
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+        let n5 = graph.add_node(());
+        let n6 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 7);
+        graph.add_edge(n1, n3, 9);
+        graph.add_edge(n1, n6, 14);
+        graph.add_edge(n2, n3, 10);
+        graph.add_edge(n2, n4, 15);
+        graph.add_edge(n3, n4, 11);
+        graph.add_edge(n3, n6, 2);
+        graph.add_edge(n4, n5, 6);
+        graph.add_edge(n5, n6, 14);
+
+        let (start, end) = (n1, n5);
+        let mut state = SearchState::new(start);
+        loop {
+            match a_star_resume(&&graph, end, no_heuristic, 1, &mut state) {
+                PathResult::Found(path) => {
+                    assert_eq!(path, vec![n5, n6, n3, n1]);
+                    break;
+                }
+                PathResult::PartialBudgetExhausted(_) => continue,
+                PathResult::NotFound => panic!("expected the resumed search to find a path"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_dijkstra_distances_and_path() {
+        let mut graph = AdjacencyMap::new();
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+        let n5 = graph.add_node(());
+        let n6 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 7);
+        graph.add_edge(n1, n3, 9);
+        graph.add_edge(n1, n6, 14);
+        graph.add_edge(n2, n3, 10);
+        graph.add_edge(n2, n4, 15);
+        graph.add_edge(n3, n4, 11);
+        graph.add_edge(n3, n6, 2);
+        graph.add_edge(n4, n5, 6);
+        graph.add_edge(n5, n6, 14);
+
+        let (dist, predecessors) = dijkstra(&&graph, n1);
+        assert_eq!(dist[&n5], 25);
+        assert_eq!(reconstruct_path(&predecessors, &n1, &n5), Some(vec![n1, n3, n6, n5]));
+    }
+
+    #[test]
+    fn test_dijkstra_unreachable_node_has_no_path() {
+        let mut graph = AdjacencyMap::new();
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+
+        graph.add_edge(n1, n2, 1);
+
+        let (dist, predecessors) = dijkstra(&&graph, n1);
+        assert!(!dist.contains_key(&n3));
+        assert_eq!(reconstruct_path(&predecessors, &n1, &n3), None);
+    }
 }
@@ -45,6 +45,86 @@ pub fn breadth_first_search<F, G: Graph>(graph: &G, start: G::NodeId, mut apply:
     }
 }
 
+#[derive(Clone)]
+pub struct DfsNode<N> {
+    value: N,
+    parent: usize,
+}
+
+impl<N: Clone> DfsNode<N> {
+    pub fn value(&self) -> N {
+        self.value.clone()
+    }
+
+    pub fn parent(&self) -> usize {
+        self.parent
+    }
+}
+
+pub fn depth_first_search<F, G: Graph>(graph: &G, start: G::NodeId, mut apply: F)
+    where F: FnMut(&[DfsNode<G::NodeId>]) -> bool,
+          G::NodeId: Clone + Hash + Eq,
+{
+    let mut visited = HashSet::new();
+    let mut visit_order = vec![];
+    let mut stack = vec![];
+
+    stack.push(DfsNode { value: start, parent: 0 });
+    while let Some(node) = stack.pop() {
+        let node_id = visit_order.len();
+        visit_order.push(node.clone());
+
+        if !apply(&visit_order) { return }
+
+        for target in graph.outgoing_edges(&node.value).filter_map(|e| graph.target(&e)) {
+            if visited.insert(target.clone()) {
+                stack.push(DfsNode { value: target, parent: node_id });
+            }
+        }
+    }
+}
+
+/// Enumerates every loop-free path from `start` to `end` via backtracking.
+pub fn all_simple_paths<G: Graph>(graph: &G, start: G::NodeId, end: G::NodeId) -> Vec<Vec<G::NodeId>>
+    where G::NodeId: Clone + Hash + Eq,
+{
+    let mut paths = vec![];
+    let mut on_path = HashSet::new();
+    let mut path = vec![start.clone()];
+
+    on_path.insert(start.clone());
+    visit_simple_paths(graph, &start, &end, &mut on_path, &mut path, &mut paths);
+
+    paths
+}
+
+fn visit_simple_paths<G: Graph>(
+    graph: &G,
+    current: &G::NodeId,
+    end: &G::NodeId,
+    on_path: &mut HashSet<G::NodeId>,
+    path: &mut Vec<G::NodeId>,
+    paths: &mut Vec<Vec<G::NodeId>>,
+)
+    where G::NodeId: Clone + Hash + Eq,
+{
+    if current == end {
+        paths.push(path.clone());
+        return;
+    }
+
+    for target in graph.outgoing_edges(current).filter_map(|e| graph.target(&e)) {
+        if on_path.insert(target.clone()) {
+            path.push(target.clone());
+            visit_simple_paths(graph, &target, end, on_path, path, paths);
+            path.pop();
+            // Remove the node so that alternate routes passing back through it are still
+            // explorable by other branches of the search.
+            on_path.remove(&target);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -55,22 +135,70 @@ mod test {
         // [1] -> [2] -> [3] -> [4] -> [5]
         let mut graph = AdjacencyMap::new();
 
-        graph.add_node(1, ());
-        graph.add_node(2, ());
-        graph.add_node(3, ());
-        graph.add_node(4, ());
-        graph.add_node(5, ());
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+        let n5 = graph.add_node(());
+
+        graph.add_arc(n1, n2, 0);
+        graph.add_arc(n2, n3, 0);
+        graph.add_arc(n3, n4, 0);
+        graph.add_arc(n4, n5, 0);
+
+        let expected = [n1, n2, n3, n4, n5];
+        let mut index = 0;
+        breadth_first_search(&&graph, n1, |visited| {
+            assert_eq!(visited.last().map(|n| n.value()), Some(expected[index]));
+            index += 1;
+            true
+        });
+    }
+
+    #[test]
+    pub fn depth_first_search_basic_test() {
+        // [1] -> [2] -> [3] -> [4] -> [5]
+        let mut graph = AdjacencyMap::new();
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+        let n5 = graph.add_node(());
 
-        graph.add_arc(1, 2, 0);
-        graph.add_arc(2, 3, 0);
-        graph.add_arc(3, 4, 0);
-        graph.add_arc(4, 5, 0);
+        graph.add_arc(n1, n2, 0);
+        graph.add_arc(n2, n3, 0);
+        graph.add_arc(n3, n4, 0);
+        graph.add_arc(n4, n5, 0);
 
-        let mut index = 1;
-        breadth_first_search(&&graph, 1, |visited| {
-            assert_eq!(visited.last().map(|n| n.value()), Some(index));
+        let expected = [n1, n2, n3, n4, n5];
+        let mut index = 0;
+        depth_first_search(&&graph, n1, |visited| {
+            assert_eq!(visited.last().map(|n| n.value()), Some(expected[index]));
             index += 1;
             true
         });
     }
+
+    #[test]
+    pub fn all_simple_paths_diamond() {
+        // [1] -> [2] -> [4]
+        //   \          /
+        //    -> [3] ->
+        let mut graph = AdjacencyMap::new();
+
+        let n1 = graph.add_node(());
+        let n2 = graph.add_node(());
+        let n3 = graph.add_node(());
+        let n4 = graph.add_node(());
+
+        graph.add_arc(n1, n2, 0);
+        graph.add_arc(n1, n3, 0);
+        graph.add_arc(n2, n4, 0);
+        graph.add_arc(n3, n4, 0);
+
+        let mut paths = all_simple_paths(&&graph, n1, n4);
+        paths.sort();
+        assert_eq!(paths, vec![vec![n1, n2, n4], vec![n1, n3, n4]]);
+    }
 }